@@ -1,20 +1,271 @@
-use axum::{response::IntoResponse, routing::get, Json};
+use askama::Template;
+use axum::{
+    http::{header, HeaderMap, StatusCode, Uri},
+    response::{Html, IntoResponse, Response},
+    Json, Router,
+};
+use axum_extra::routing::{RouterExt, TypedPath};
 use lambda_http::Error;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use tower::{Layer, ServiceBuilder};
+use tower_http::{
+    cors::CorsLayer,
+    normalize_path::{NormalizePath, NormalizePathLayer},
+    request_id::MakeRequestUuid,
+    trace::TraceLayer,
+    ServiceBuilderExt,
+};
+
+/// `GET /`
+#[derive(TypedPath)]
+#[typed_path("/")]
+pub struct Root;
+
+/// `GET /ping/{name}`
+#[derive(TypedPath, Deserialize)]
+#[typed_path("/ping/{name}")]
+pub struct PingByName {
+    name: String,
+}
+
+/// `GET /health`
+#[derive(TypedPath)]
+#[typed_path("/health")]
+pub struct HealthCheck;
 
 #[derive(Serialize)]
 pub struct Ping {
-    message: &'static str,
+    message: String,
+}
+
+#[derive(Template)]
+#[template(path = "ping.html")]
+pub struct PingTemplate {
+    message: String,
+}
+
+/// Wraps a JSON payload and its HTML template counterpart, and picks between
+/// them based on the client's `Accept` header so a single handler can back
+/// both a browser page and a REST client.
+pub struct Negotiated<J, H> {
+    headers: HeaderMap,
+    json: J,
+    html: H,
+}
+
+impl<J, H> Negotiated<J, H> {
+    pub fn new(headers: HeaderMap, json: J, html: H) -> Self {
+        Self { headers, json, html }
+    }
+}
+
+impl<J, H> IntoResponse for Negotiated<J, H>
+where
+    J: Serialize,
+    H: Template,
+{
+    fn into_response(self) -> Response {
+        let wants_html = self
+            .headers
+            .get(header::ACCEPT)
+            .and_then(|value| value.to_str().ok())
+            .is_some_and(|accept| accept.contains("text/html"));
+
+        if wants_html {
+            match self.html.render() {
+                Ok(body) => Html(body).into_response(),
+                Err(err) => ApiError::internal(err).into_response(),
+            }
+        } else {
+            Json(self.json).into_response()
+        }
+    }
+}
+
+pub async fn ping(_: Root, headers: HeaderMap) -> impl IntoResponse {
+    let message = "hello from rust :)".to_string();
+    Negotiated::new(
+        headers,
+        Ping {
+            message: message.clone(),
+        },
+        PingTemplate { message },
+    )
+}
+
+pub async fn ping_by_name(PingByName { name }: PingByName, headers: HeaderMap) -> impl IntoResponse {
+    let message = format!("hello, {name}, from rust :)");
+    Negotiated::new(
+        headers,
+        Ping {
+            message: message.clone(),
+        },
+        PingTemplate { message },
+    )
+}
+
+/// Result of a health/readiness probe, modeled after the `health check response`
+/// draft (RFC draft-inadarei-api-health-check): a check either passes, passes
+/// with a warning, or fails, optionally carrying a human-readable detail.
+#[derive(Serialize)]
+#[serde(tag = "status", content = "output", rename_all = "lowercase")]
+pub enum Status {
+    Pass,
+    Warn(Option<String>),
+    Fail(Option<String>),
+}
+
+impl IntoResponse for Status {
+    fn into_response(self) -> Response {
+        let code = match &self {
+            Status::Pass | Status::Warn(_) => StatusCode::OK,
+            Status::Fail(_) => StatusCode::SERVICE_UNAVAILABLE,
+        };
+        (code, Json(self)).into_response()
+    }
+}
+
+impl<E: std::error::Error> From<E> for Status {
+    fn from(err: E) -> Self {
+        Status::Fail(Some(err.to_string()))
+    }
 }
 
-pub async fn ping() -> impl IntoResponse {
-    Json(Ping {
-        message: "hello from rust :)",
-    })
+pub async fn health(_: HealthCheck) -> impl IntoResponse {
+    Status::Pass
+}
+
+/// Shared error envelope returned by both handler errors and the 404
+/// fallback, so API consumers only ever parse one error shape.
+#[derive(Serialize)]
+pub struct ApiError {
+    error: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detail: Option<String>,
+    #[serde(skip)]
+    status: StatusCode,
+}
+
+impl ApiError {
+    pub fn not_found(uri: Uri) -> Self {
+        Self {
+            error: "not_found",
+            path: Some(uri.to_string()),
+            detail: None,
+            status: StatusCode::NOT_FOUND,
+        }
+    }
+
+    pub fn internal(err: impl std::error::Error) -> Self {
+        Self {
+            error: "internal_error",
+            path: None,
+            detail: Some(err.to_string()),
+            status: StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+}
+
+impl IntoResponse for ApiError {
+    fn into_response(self) -> Response {
+        let status = self.status;
+        (status, Json(self)).into_response()
+    }
+}
+
+pub async fn not_found(uri: Uri) -> impl IntoResponse {
+    ApiError::not_found(uri)
+}
+
+/// Builds the full service stack, including trailing-slash normalization,
+/// separately from `main` so it can be exercised with
+/// `tower::ServiceExt::oneshot` in tests without a live Lambda runtime.
+pub fn app() -> NormalizePath<Router> {
+    let middleware = ServiceBuilder::new()
+        .set_x_request_id(MakeRequestUuid)
+        .layer(TraceLayer::new_for_http())
+        .propagate_x_request_id()
+        .layer(CorsLayer::permissive());
+
+    let router = Router::new()
+        .typed_get(ping)
+        .typed_get(ping_by_name)
+        .typed_get(health)
+        .fallback(not_found)
+        .layer(middleware);
+
+    NormalizePathLayer::trim_trailing_slash().layer(router)
 }
 
 #[tokio::main]
 pub async fn main() -> Result<(), Error> {
-    let app = axum::Router::new().route("/", get(ping));
-    lambda_http::run(app).await
+    lambda_http::run(app()).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{
+        body::{to_bytes, Body},
+        http::Request,
+    };
+    use tower::ServiceExt;
+
+    #[tokio::test]
+    async fn health_check_passes() {
+        let response = app()
+            .oneshot(Request::builder().uri("/health").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    #[tokio::test]
+    async fn unmatched_route_returns_json_error_envelope() {
+        let response = app()
+            .oneshot(Request::builder().uri("/nope").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["error"], "not_found");
+        assert_eq!(json["path"], "/nope");
+    }
+
+    #[tokio::test]
+    async fn ping_defaults_to_json() {
+        let response = app()
+            .oneshot(Request::builder().uri("/").body(Body::empty()).unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+    }
+
+    #[tokio::test]
+    async fn ping_renders_html_when_requested() {
+        let response = app()
+            .oneshot(
+                Request::builder()
+                    .uri("/")
+                    .header(header::ACCEPT, "text/html")
+                    .body(Body::empty())
+                    .unwrap(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/html; charset=utf-8"
+        );
+    }
 }